@@ -12,6 +12,73 @@ extern crate alloc;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 
+/// Wraps `content` into one `marker`-prefixed line per `max_width`-bounded chunk, breaking on
+/// whitespace and re-prefixing every wrapped line with `line_indent` and `marker`.
+fn wrap_doc_comment(content: &str, marker: &str, line_indent: &str, max_width: usize) -> String {
+  let prefix_len = line_indent.len() + marker.len() + 1;
+  let mut result = String::new();
+  let mut line = String::new();
+
+  for word in content.split_whitespace() {
+    let candidate_len = prefix_len + line.len() + if line.is_empty() { 0 } else { 1 } + word.len();
+
+    if !line.is_empty() && candidate_len > max_width {
+      result.push_str(line_indent);
+      result.push_str(marker);
+      result.push(' ');
+      result.push_str(&line);
+      result.push('\n');
+      line.clear();
+    }
+
+    if !line.is_empty() {
+      line.push(' ');
+    }
+    line.push_str(word);
+  }
+
+  if !line.is_empty() || result.is_empty() {
+    result.push_str(line_indent);
+    result.push_str(marker);
+    if !line.is_empty() {
+      result.push(' ');
+      result.push_str(&line);
+    }
+    result.push('\n');
+  }
+
+  result
+}
+
+/// Escapes the literal `$`, `{`, and `}` characters that the LSP snippet syntax treats as
+/// special, so plain text survives round-tripping through an editor's snippet expansion.
+fn escape_snippet(content: &str) -> String {
+  let mut result = String::with_capacity(content.len());
+
+  for ch in content.chars() {
+    if ch == '$' || ch == '{' || ch == '}' {
+      result.push('\\');
+    }
+    result.push(ch);
+  }
+
+  result
+}
+
+/// Renders a snippet tabstop: `$0` for the final stop (`index == 0`), `${index:default}` for
+/// any other stop in snippet mode, or plain `default` text when snippet mode is off.
+fn render_placeholder(index: usize, default: &str, snippet_mode: bool) -> String {
+  if !snippet_mode {
+    return default.to_string();
+  }
+
+  if index == 0 {
+    return String::from("$0");
+  }
+
+  format!("${{{}:{}}}", index, escape_snippet(default))
+}
+
 /// Represents a structured space for managing code elements.
 #[derive(Debug)]
 pub struct CodeSpace {
@@ -19,6 +86,11 @@ pub struct CodeSpace {
   pub indent_char: char,
   /// The depth of indentation.
   pub indent_depth: usize,
+  /// The maximum line width before a doc comment wraps onto additional lines.
+  pub max_width: usize,
+  /// When set, `Code::Placeholder` renders as an LSP-style snippet tabstop (and surrounding
+  /// text has its literal `$`, `{`, `}` escaped) instead of substituting its default text.
+  pub snippet_mode: bool,
   /// A collection of code elements.
   codes: Vec<Code>,
 }
@@ -29,6 +101,8 @@ impl CodeSpace {
     Self {
       indent_char: ' ',
       indent_depth: 2,
+      max_width: 100,
+      snippet_mode: false,
       codes: vec![],
     }
   }
@@ -66,6 +140,135 @@ impl CodeSpace {
     }
     self
   }
+
+  /// Inserts a doc comment, rendered as `///` (or `//!` when `is_inner` is set) and wrapped onto
+  /// additional lines once it would exceed `max_width`.
+  pub fn insert_doc(mut self, content: impl ToString, is_inner: bool) -> Self {
+    self.codes.push(Code::DocComment {
+      content: content.to_string(),
+      is_inner,
+    });
+    self
+  }
+
+  /// Inserts a `//` line comment.
+  pub fn insert_comment(mut self, content: impl ToString) -> Self {
+    self.codes.push(Code::LineComment(content.to_string()));
+    self
+  }
+
+  /// Inserts an attribute, rendered as `#[...]` on its own line immediately above the following
+  /// item.
+  pub fn insert_attribute(mut self, content: impl ToString) -> Self {
+    self.codes.push(Code::Attribute(content.to_string()));
+    self
+  }
+
+  /// Inserts a snippet tabstop. Renders as `${index:default}` (or `$0` when `index` is `0`,
+  /// marking the final stop) in snippet mode, and as plain `default` text otherwise.
+  pub fn insert_placeholder(mut self, index: usize, default: impl ToString) -> Self {
+    self.codes.push(Code::Placeholder {
+      index,
+      default: default.to_string(),
+    });
+    self
+  }
+
+  /// Opens a block under the given `signature`, writing its contents through a closure-scoped
+  /// `Formatter` rather than an intermediate `Block` tree.
+  pub fn block(mut self, signature: impl ToString, f: impl FnOnce(&mut Formatter)) -> Self {
+    let mut formatter = Formatter::new();
+    formatter.indent_char = self.indent_char;
+    formatter.indent_depth = self.indent_depth;
+
+    formatter.write(signature);
+    formatter.block(f);
+
+    self.codes.push(Code::Raw(formatter.to_string()));
+    self
+  }
+}
+
+/// A closure-scoped writer that renders directly into a string, tracking the current indentation
+/// level so callers don't have to repeat `Block`'s `repeat`/`push_str` dance by hand.
+#[derive(Debug)]
+pub struct Formatter {
+  /// The character used for indentation.
+  pub indent_char: char,
+  /// The depth of indentation.
+  pub indent_depth: usize,
+  level: usize,
+  buf: String,
+  at_line_start: bool,
+}
+
+impl Formatter {
+  /// Creates a new, empty `Formatter` with default settings.
+  pub fn new() -> Self {
+    Self {
+      indent_char: ' ',
+      indent_depth: 2,
+      level: 0,
+      buf: String::new(),
+      at_line_start: true,
+    }
+  }
+
+  fn write_indent(&mut self) {
+    let indent = self.indent_char.to_string().repeat(self.indent_depth);
+    for _ in 0..self.level {
+      self.buf.push_str(&indent);
+    }
+  }
+
+  /// Writes `content` at the current position, inserting the current indent first if at the
+  /// start of a line.
+  pub fn write(&mut self, content: impl ToString) -> &mut Self {
+    if self.at_line_start {
+      self.write_indent();
+      self.at_line_start = false;
+    }
+    self.buf.push_str(&content.to_string());
+    self
+  }
+
+  /// Writes `content` followed by a newline, inserting the current indent first if at the start
+  /// of a line.
+  pub fn write_line(&mut self, content: impl ToString) -> &mut Self {
+    self.write(content);
+    self.buf.push('\n');
+    self.at_line_start = true;
+    self
+  }
+
+  /// Writes an empty line.
+  pub fn new_line(&mut self) -> &mut Self {
+    self.buf.push('\n');
+    self.at_line_start = true;
+    self
+  }
+
+  /// Opens a brace-delimited block: writes `{` (preceded by a space when continuing a
+  /// signature already written on the current line), raises the indent level for the duration
+  /// of `f`, then closes it with `}` back at the current indent.
+  pub fn block(&mut self, f: impl FnOnce(&mut Self)) -> &mut Self {
+    if self.at_line_start {
+      self.write_line("{");
+    } else {
+      self.write_line(" {");
+    }
+    self.level += 1;
+    f(self);
+    self.level -= 1;
+    self.write_line("}");
+    self
+  }
+}
+
+impl ToString for Formatter {
+  fn to_string(&self) -> String {
+    self.buf.clone()
+  }
 }
 
 /// Represents different kinds of code structures.
@@ -73,10 +276,22 @@ impl CodeSpace {
 pub enum Code {
   /// Represents an empty line.
   EmptyLine,
-  /// Represents a line of code.
+  /// Represents a line of code. May itself span multiple physical lines (e.g. a multi-line
+  /// item's `to_string()`), in which case each physical line gets the current indent.
   Line(String),
   /// Represents a block of code.
   Block(Block),
+  /// Represents pre-rendered content produced by a closure-scoped `Formatter`.
+  Raw(String),
+  /// A doc comment, rendered as `///` or (when `is_inner` is set) `//!`.
+  DocComment { content: String, is_inner: bool },
+  /// A `//` line comment.
+  LineComment(String),
+  /// An attribute, rendered as `#[...]` on its own line immediately above the following item.
+  Attribute(String),
+  /// A snippet tabstop. `index` `0` marks the final stop (`$0`); any other `index` renders as
+  /// `${index:default}` in snippet mode, or plain `default` text otherwise.
+  Placeholder { index: usize, default: String },
 }
 
 /// Represents a block of code, which may contain lines or nested blocks.
@@ -86,12 +301,21 @@ pub struct Block {
   signature: Option<BlockSignature>,
   /// Code elements contained in this block.
   codes: Vec<Code>,
+  /// The maximum line width before a `Function` signature wraps its params and where clause
+  /// onto their own lines.
+  max_width: usize,
+  /// When set, `Code::Placeholder` renders as an LSP-style snippet tabstop (and surrounding
+  /// text has its literal `$`, `{`, `}` escaped) instead of substituting its default text.
+  snippet_mode: bool,
 }
 
 impl Block {
   /// Creates a new, empty `Block`.
   pub fn new() -> Self {
-    Self { ..Default::default() }
+    Self {
+      max_width: 100,
+      ..Default::default()
+    }
   }
 
   /// Sets the signature of the block.
@@ -100,6 +324,18 @@ impl Block {
     self
   }
 
+  /// Sets the max width used to decide when a `Function` signature wraps.
+  pub fn set_max_width(mut self, max_width: usize) -> Self {
+    self.max_width = max_width;
+    self
+  }
+
+  /// Toggles snippet rendering mode for this block's `Code::Placeholder` entries and text.
+  pub fn set_snippet_mode(mut self, snippet_mode: bool) -> Self {
+    self.snippet_mode = snippet_mode;
+    self
+  }
+
   /// Inserts a line of code into the block.
   pub fn insert_line(mut self, content: impl ToString) -> Self {
     self.codes.push(Code::Line(content.to_string()));
@@ -134,6 +370,40 @@ impl Block {
     self
   }
 
+  /// Inserts a doc comment into the block, rendered as `///` (or `//!` when `is_inner` is set)
+  /// and wrapped onto additional lines once it would exceed `max_width`.
+  pub fn insert_doc(mut self, content: impl ToString, is_inner: bool) -> Self {
+    self.codes.push(Code::DocComment {
+      content: content.to_string(),
+      is_inner,
+    });
+    self
+  }
+
+  /// Inserts a `//` line comment into the block.
+  pub fn insert_comment(mut self, content: impl ToString) -> Self {
+    self.codes.push(Code::LineComment(content.to_string()));
+    self
+  }
+
+  /// Inserts an attribute into the block, rendered as `#[...]` on its own line immediately above
+  /// the following item.
+  pub fn insert_attribute(mut self, content: impl ToString) -> Self {
+    self.codes.push(Code::Attribute(content.to_string()));
+    self
+  }
+
+  /// Inserts a snippet tabstop into the block. Renders as `${index:default}` (or `$0` when
+  /// `index` is `0`, marking the final stop) in snippet mode, and as plain `default` text
+  /// otherwise.
+  pub fn insert_placeholder(mut self, index: usize, default: impl ToString) -> Self {
+    self.codes.push(Code::Placeholder {
+      index,
+      default: default.to_string(),
+    });
+    self
+  }
+
   /// Formats the block into a string with the given indentation depth.
   fn to_string_with_indent(&self, depth: usize, indent: &str) -> String {
     let mut result = String::new();
@@ -141,9 +411,27 @@ impl Block {
 
     result.push_str(&current_indent);
 
+    if let Some(signature @ (BlockSignature::Struct { .. } | BlockSignature::Enum { .. })) =
+      &self.signature
+    {
+      // Struct/Enum already render a complete, self-contained item (their own braces and
+      // field/variant list), so there's no separate brace-delimited body to wrap it in.
+      result.push_str(&signature.render(depth, indent, self.max_width));
+      result.push('\n');
+      return result;
+    }
+
     if let Some(signature) = &self.signature {
-      result.push_str(&signature.to_string());
-      result.push(' ');
+      let rendered = signature.render(depth, indent, self.max_width);
+      result.push_str(&rendered);
+
+      if rendered.ends_with('\n') {
+        // The signature already broke onto multiple lines (e.g. a wrapped `where` clause), so
+        // the opening brace gets its own indented line instead of trailing the last one.
+        result.push_str(&current_indent);
+      } else {
+        result.push(' ');
+      }
     }
 
     result.push_str("{\n");
@@ -152,13 +440,76 @@ impl Block {
       match code {
         Code::EmptyLine => result.push('\n'),
         Code::Line(line) => {
+          // `line` may itself span multiple physical lines (e.g. a multi-line item's
+          // `to_string()` passed to `insert_line`), so every physical line gets the current
+          // indent rather than just the first.
+          for physical_line in line.lines() {
+            if !physical_line.is_empty() {
+              result.push_str(&current_indent);
+              result.push_str(indent);
+            }
+            if self.snippet_mode {
+              result.push_str(&escape_snippet(physical_line));
+            } else {
+              result.push_str(physical_line);
+            }
+            result.push('\n');
+          }
+        }
+        Code::Block(block) => {
+          result.push_str(&block.to_string_with_indent(depth + 1, indent));
+        }
+        Code::Raw(raw) => {
+          for line in raw.lines() {
+            if !line.is_empty() {
+              result.push_str(&current_indent);
+              result.push_str(indent);
+            }
+            if self.snippet_mode {
+              result.push_str(&escape_snippet(line));
+            } else {
+              result.push_str(line);
+            }
+            result.push('\n');
+          }
+        }
+        Code::DocComment { content, is_inner } => {
+          let marker = if *is_inner { "//!" } else { "///" };
+          let line_indent = format!("{}{}", current_indent, indent);
+          let content = if self.snippet_mode {
+            escape_snippet(content)
+          } else {
+            content.clone()
+          };
+          result.push_str(&wrap_doc_comment(&content, marker, &line_indent, self.max_width));
+        }
+        Code::LineComment(content) => {
           result.push_str(&current_indent);
           result.push_str(indent);
-          result.push_str(line);
+          result.push_str("// ");
+          if self.snippet_mode {
+            result.push_str(&escape_snippet(content));
+          } else {
+            result.push_str(content);
+          }
           result.push('\n');
         }
-        Code::Block(block) => {
-          result.push_str(&block.to_string_with_indent(depth + 1, indent));
+        Code::Attribute(content) => {
+          result.push_str(&current_indent);
+          result.push_str(indent);
+          result.push_str("#[");
+          if self.snippet_mode {
+            result.push_str(&escape_snippet(content));
+          } else {
+            result.push_str(content);
+          }
+          result.push_str("]\n");
+        }
+        Code::Placeholder { index, default } => {
+          result.push_str(&current_indent);
+          result.push_str(indent);
+          result.push_str(&render_placeholder(*index, default, self.snippet_mode));
+          result.push('\n');
         }
       }
     }
@@ -197,6 +548,40 @@ pub enum BlockSignature {
     return_type: Option<String>,
     where_clauses: Vec<(String, String)>,
   },
+  /// A struct declaration, rendered as a complete item including its field list and braces.
+  /// Self-contained, unlike every other variant here: insert its `to_string()` directly (e.g.
+  /// via `insert_line`) rather than passing it to `Block::set_signature`.
+  Struct {
+    visibility: Option<SignatureVisibility>,
+    name: String,
+    generics: Vec<String>,
+    where_clauses: Vec<(String, String)>,
+    fields: Vec<(Option<SignatureVisibility>, String, String)>,
+  },
+  /// An enum declaration, rendered as a complete item including its variant list and braces.
+  /// Self-contained, unlike every other variant here: insert its `to_string()` directly (e.g.
+  /// via `insert_line`) rather than passing it to `Block::set_signature`.
+  Enum {
+    visibility: Option<SignatureVisibility>,
+    name: String,
+    generics: Vec<String>,
+    variants: Vec<String>,
+  },
+  /// A trait declaration.
+  Trait {
+    visibility: Option<SignatureVisibility>,
+    name: String,
+    generics: Vec<String>,
+    supertraits: Vec<String>,
+    where_clauses: Vec<(String, String)>,
+  },
+  /// An `impl` block, either an inherent impl or a trait impl when `trait_` is set.
+  Impl {
+    generics: Vec<String>,
+    trait_: Option<String>,
+    self_ty: String,
+    where_clauses: Vec<(String, String)>,
+  },
   /// A custom block signature.
   Custom(String),
 }
@@ -210,12 +595,54 @@ impl ToString for CodeSpace {
       match code {
         Code::EmptyLine => result.push('\n'),
         Code::Line(line) => {
-          result.push_str(&line);
+          if self.snippet_mode {
+            result.push_str(&escape_snippet(line));
+          } else {
+            result.push_str(line);
+          }
           result.push('\n');
         }
         Code::Block(block) => {
           result.push_str(&block.to_string_with_indent(0, &indent));
         }
+        Code::Raw(raw) => {
+          if self.snippet_mode {
+            result.push_str(&escape_snippet(raw));
+          } else {
+            result.push_str(raw);
+          }
+        }
+        Code::DocComment { content, is_inner } => {
+          let marker = if *is_inner { "//!" } else { "///" };
+          let content = if self.snippet_mode {
+            escape_snippet(content)
+          } else {
+            content.clone()
+          };
+          result.push_str(&wrap_doc_comment(&content, marker, "", self.max_width));
+        }
+        Code::LineComment(content) => {
+          result.push_str("// ");
+          if self.snippet_mode {
+            result.push_str(&escape_snippet(content));
+          } else {
+            result.push_str(content);
+          }
+          result.push('\n');
+        }
+        Code::Attribute(content) => {
+          result.push_str("#[");
+          if self.snippet_mode {
+            result.push_str(&escape_snippet(content));
+          } else {
+            result.push_str(content);
+          }
+          result.push_str("]\n");
+        }
+        Code::Placeholder { index, default } => {
+          result.push_str(&render_placeholder(*index, default, self.snippet_mode));
+          result.push('\n');
+        }
       }
     }
 
@@ -229,6 +656,222 @@ impl ToString for Block {
   }
 }
 
+impl BlockSignature {
+  /// Renders this signature, wrapping a `Function`'s params and where clause onto their own
+  /// lines when the compact, single-line form would exceed `max_width` at the given `depth`.
+  ///
+  /// `Struct`/`Enum` render their own complete, self-contained item (own braces and
+  /// field/variant list, indented for `depth`) via [`Self::to_string_with_indent`]; the caller
+  /// (`Block::to_string_with_indent`) recognizes this and skips wrapping it in another brace
+  /// pair. Every other signature kind ignores `depth`/`max_width` and renders as `ToString` does.
+  fn render(&self, depth: usize, indent: &str, max_width: usize) -> String {
+    if matches!(self, BlockSignature::Struct { .. } | BlockSignature::Enum { .. }) {
+      return self.to_string_with_indent(depth, indent);
+    }
+
+    let BlockSignature::Function {
+      visibility,
+      is_async,
+      name,
+      generics,
+      params,
+      return_type,
+      where_clauses,
+    } = self
+    else {
+      return self.to_string();
+    };
+
+    let base_indent = indent.repeat(depth);
+    let param_indent = indent.repeat(depth + 1);
+
+    let mut head = String::new();
+
+    if let Some(visibility) = visibility {
+      head.push_str(&visibility.to_string());
+      head.push(' ');
+    }
+
+    if *is_async {
+      head.push_str("async ");
+    }
+
+    head.push_str("fn ");
+    head.push_str(name);
+
+    if !generics.is_empty() {
+      head.push('<');
+      head.push_str(&generics.join(", "));
+      head.push('>');
+    }
+
+    let params_oneline = params
+      .iter()
+      .map(|(name, ty)| format!("{}: {}", name, ty))
+      .collect::<Vec<_>>()
+      .join(", ");
+
+    let mut oneline_signature = format!("{}{}({})", base_indent, head, params_oneline);
+    if let Some(return_type) = return_type {
+      oneline_signature.push_str(" -> ");
+      oneline_signature.push_str(return_type);
+    }
+
+    let mut result = head.clone();
+
+    if oneline_signature.len() <= max_width {
+      result.push('(');
+      result.push_str(&params_oneline);
+      result.push(')');
+    } else {
+      result.push_str("(\n");
+      for (name, ty) in params {
+        result.push_str(&param_indent);
+        result.push_str(name);
+        result.push_str(": ");
+        result.push_str(ty);
+        result.push_str(",\n");
+      }
+      result.push_str(&base_indent);
+      result.push(')');
+    }
+
+    if let Some(return_type) = return_type {
+      result.push_str(" -> ");
+      result.push_str(return_type);
+    }
+
+    if !where_clauses.is_empty() {
+      let where_oneline = format!(
+        "{}where {}",
+        base_indent,
+        where_clauses
+          .iter()
+          .map(|(param, constraint)| format!("{}: {}", param, constraint))
+          .collect::<Vec<_>>()
+          .join(", ")
+      );
+
+      if where_oneline.len() <= max_width {
+        result.push('\n');
+        result.push_str(&where_oneline);
+      } else {
+        result.push('\n');
+        result.push_str(&base_indent);
+        result.push_str("where\n");
+        for (param, constraint) in where_clauses {
+          result.push_str(&param_indent);
+          result.push_str(param);
+          result.push_str(": ");
+          result.push_str(constraint);
+          result.push_str(",\n");
+        }
+        // Left ending in a newline (instead of a trailing `base_indent`) so the caller knows to
+        // put the opening brace on its own indented line rather than trailing this one.
+      }
+    }
+
+    result
+  }
+
+  /// Renders a `Struct`/`Enum` signature's field or variant list indented one level past
+  /// `depth`, using `indent` instead of a hardcoded two spaces — matching how
+  /// `Block::to_string_with_indent` indents nested content. Every other signature kind ignores
+  /// `depth`/`indent` and renders identically to `ToString`.
+  pub fn to_string_with_indent(&self, depth: usize, indent: &str) -> String {
+    let current_indent = indent.repeat(depth);
+    let field_indent = indent.repeat(depth + 1);
+
+    match self {
+      BlockSignature::Struct {
+        visibility,
+        name,
+        generics,
+        where_clauses,
+        fields,
+      } => {
+        let mut result = String::new();
+
+        if let Some(visibility) = visibility {
+          result.push_str(&visibility.to_string());
+          result.push(' ');
+        }
+
+        result.push_str("struct ");
+        result.push_str(name);
+
+        if !generics.is_empty() {
+          result.push('<');
+          result.push_str(&generics.join(", "));
+          result.push('>');
+        }
+
+        if !where_clauses.is_empty() {
+          result.push_str("\nwhere ");
+          result.push_str(
+            &where_clauses
+              .iter()
+              .map(|(param, constraint)| format!("{}: {}", param, constraint))
+              .collect::<Vec<_>>()
+              .join(", "),
+          );
+        }
+
+        result.push_str(" {\n");
+        for (visibility, name, ty) in fields {
+          result.push_str(&field_indent);
+          if let Some(visibility) = visibility {
+            result.push_str(&visibility.to_string());
+            result.push(' ');
+          }
+          result.push_str(name);
+          result.push_str(": ");
+          result.push_str(ty);
+          result.push_str(",\n");
+        }
+        result.push_str(&current_indent);
+        result.push('}');
+
+        result
+      }
+      BlockSignature::Enum {
+        visibility,
+        name,
+        generics,
+        variants,
+      } => {
+        let mut result = String::new();
+
+        if let Some(visibility) = visibility {
+          result.push_str(&visibility.to_string());
+          result.push(' ');
+        }
+
+        result.push_str("enum ");
+        result.push_str(name);
+
+        if !generics.is_empty() {
+          result.push('<');
+          result.push_str(&generics.join(", "));
+          result.push('>');
+        }
+
+        result.push_str(" {\n");
+        for variant in variants {
+          result.push_str(&field_indent);
+          result.push_str(variant);
+          result.push_str(",\n");
+        }
+        result.push_str(&current_indent);
+        result.push('}');
+
+        result
+      }
+      _ => self.to_string(),
+    }
+  }
+}
+
 impl ToString for BlockSignature {
   fn to_string(&self) -> String {
     match self {
@@ -302,6 +945,88 @@ impl ToString for BlockSignature {
 
         result
       }
+      BlockSignature::Struct { .. } | BlockSignature::Enum { .. } => {
+        self.to_string_with_indent(0, "  ")
+      }
+      BlockSignature::Trait {
+        visibility,
+        name,
+        generics,
+        supertraits,
+        where_clauses,
+      } => {
+        let mut result = String::new();
+
+        if let Some(visibility) = visibility {
+          result.push_str(&visibility.to_string());
+          result.push(' ');
+        }
+
+        result.push_str("trait ");
+        result.push_str(name);
+
+        if !generics.is_empty() {
+          result.push('<');
+          result.push_str(&generics.join(", "));
+          result.push('>');
+        }
+
+        if !supertraits.is_empty() {
+          result.push_str(": ");
+          result.push_str(&supertraits.join(" + "));
+        }
+
+        if !where_clauses.is_empty() {
+          result.push_str("\nwhere ");
+          result.push_str(
+            &where_clauses
+              .iter()
+              .map(|(param, constraint)| format!("{}: {}", param, constraint))
+              .collect::<Vec<_>>()
+              .join(", "),
+          );
+        }
+
+        result
+      }
+      BlockSignature::Impl {
+        generics,
+        trait_,
+        self_ty,
+        where_clauses,
+      } => {
+        let mut result = String::new();
+
+        result.push_str("impl");
+
+        if !generics.is_empty() {
+          result.push('<');
+          result.push_str(&generics.join(", "));
+          result.push('>');
+        }
+
+        result.push(' ');
+
+        if let Some(trait_) = trait_ {
+          result.push_str(trait_);
+          result.push_str(" for ");
+        }
+
+        result.push_str(self_ty);
+
+        if !where_clauses.is_empty() {
+          result.push_str("\nwhere ");
+          result.push_str(
+            &where_clauses
+              .iter()
+              .map(|(param, constraint)| format!("{}: {}", param, constraint))
+              .collect::<Vec<_>>()
+              .join(", "),
+          );
+        }
+
+        result
+      }
       BlockSignature::Custom(signature) => signature.clone(),
     }
   }
@@ -55,3 +55,315 @@ fn test_example() {
 
   file.write_all(code.as_bytes()).expect("Failed to write to test file");
 }
+
+#[test]
+fn test_closure_block() {
+  let mut file = helper::prepare_output_dir("closure_block");
+
+  let code = CodeSpace::new()
+    .block("fn main()", |f| {
+      f.write_line("let x = 42;");
+      f.block(|f| {
+        f.write_line("println!(\"nested\");");
+      });
+    })
+    .to_string();
+
+  assert_eq!(
+    code,
+    "fn main() {\n  let x = 42;\n  {\n    println!(\"nested\");\n  }\n}\n"
+  );
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}
+
+#[test]
+fn test_item_signatures() {
+  let mut file = helper::prepare_output_dir("item_signatures");
+
+  let code = CodeSpace::new()
+    .insert_line(
+      BlockSignature::Struct {
+        visibility: Some(SignatureVisibility::Pub),
+        name: String::from("Point"),
+        generics: vec![],
+        where_clauses: vec![],
+        fields: vec![
+          (Some(SignatureVisibility::Pub), String::from("x"), String::from("i32")),
+          (Some(SignatureVisibility::Pub), String::from("y"), String::from("i32")),
+        ],
+      }
+      .to_string(),
+    )
+    .insert_new_line()
+    .insert_line(
+      BlockSignature::Enum {
+        visibility: Some(SignatureVisibility::Pub),
+        name: String::from("Shape"),
+        generics: vec![],
+        variants: vec![String::from("Circle"), String::from("Square")],
+      }
+      .to_string(),
+    )
+    .insert_new_line()
+    .insert_block(
+      Block::new()
+        .set_signature(Some(BlockSignature::Trait {
+          visibility: Some(SignatureVisibility::Pub),
+          name: String::from("Area"),
+          generics: vec![],
+          supertraits: vec![],
+          where_clauses: vec![],
+        }))
+        .insert_line("fn area(&self) -> f64;"),
+    )
+    .insert_new_line()
+    .insert_block(
+      Block::new()
+        .set_signature(Some(BlockSignature::Impl {
+          generics: vec![],
+          trait_: Some(String::from("Area")),
+          self_ty: String::from("Point"),
+          where_clauses: vec![],
+        }))
+        .insert_line("fn area(&self) -> f64 { 0.0 }"),
+    )
+    .to_string();
+
+  assert_eq!(
+    code,
+    "pub struct Point {\n  \
+       pub x: i32,\n  \
+       pub y: i32,\n\
+     }\n\
+     \n\
+     pub enum Shape {\n  \
+       Circle,\n  \
+       Square,\n\
+     }\n\
+     \n\
+     pub trait Area {\n  \
+       fn area(&self) -> f64;\n\
+     }\n\
+     \n\
+     impl Area for Point {\n  \
+       fn area(&self) -> f64 { 0.0 }\n\
+     }\n"
+  );
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}
+
+#[test]
+fn test_item_signatures_nested_in_module() {
+  let mut file = helper::prepare_output_dir("item_signatures_nested_in_module");
+
+  let code = CodeSpace::new()
+    .insert_block(
+      Block::new()
+        .set_signature(Some(BlockSignature::Module {
+          visibility: Some(SignatureVisibility::Pub),
+          name: String::from("geo"),
+        }))
+        .insert_line(
+          BlockSignature::Struct {
+            visibility: Some(SignatureVisibility::Pub),
+            name: String::from("Point"),
+            generics: vec![],
+            where_clauses: vec![],
+            fields: vec![
+              (Some(SignatureVisibility::Pub), String::from("x"), String::from("i32")),
+              (Some(SignatureVisibility::Pub), String::from("y"), String::from("i32")),
+            ],
+          }
+          .to_string(),
+        )
+        .insert_new_line()
+        .insert_line(
+          BlockSignature::Enum {
+            visibility: Some(SignatureVisibility::Pub),
+            name: String::from("Shape"),
+            generics: vec![],
+            variants: vec![String::from("Circle"), String::from("Square")],
+          }
+          .to_string(),
+        ),
+    )
+    .to_string();
+
+  assert_eq!(
+    code,
+    "pub mod geo {\n  \
+       pub struct Point {\n    \
+         pub x: i32,\n    \
+         pub y: i32,\n  \
+       }\n\
+       \n  \
+       pub enum Shape {\n    \
+         Circle,\n    \
+         Square,\n  \
+       }\n\
+     }\n"
+  );
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}
+
+#[test]
+fn test_wide_function_signature() {
+  let mut file = helper::prepare_output_dir("wide_function_signature");
+
+  let code = CodeSpace::new()
+    .insert_block(
+      Block::new()
+        .set_signature(Some(BlockSignature::Function {
+          visibility: Some(SignatureVisibility::Pub),
+          is_async: false,
+          name: String::from("process_everything"),
+          generics: vec![String::from("T"), String::from("U")],
+          params: vec![
+            (String::from("first_argument"), String::from("SomeVeryLongTypeName")),
+            (String::from("second_argument"), String::from("AnotherVeryLongTypeName")),
+            (String::from("third_argument"), String::from("YetAnotherLongTypeName")),
+          ],
+          return_type: Some(String::from("Result<(), SomeVeryLongErrorTypeName>")),
+          where_clauses: vec![
+            (String::from("T"), String::from("Clone + Send + Sync + 'static")),
+            (String::from("U"), String::from("Default + Debug")),
+          ],
+        }))
+        .insert_line("todo!()"),
+    )
+    .to_string();
+
+  assert_eq!(
+    code,
+    "pub fn process_everything<T, U>(\n  \
+       first_argument: SomeVeryLongTypeName,\n  \
+       second_argument: AnotherVeryLongTypeName,\n  \
+       third_argument: YetAnotherLongTypeName,\n\
+     ) -> Result<(), SomeVeryLongErrorTypeName>\n\
+     where T: Clone + Send + Sync + 'static, U: Default + Debug {\n  \
+       todo!()\n\
+     }\n"
+  );
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}
+
+#[test]
+fn test_function_signature_where_overflow() {
+  let mut file = helper::prepare_output_dir("function_signature_where_overflow");
+
+  let code = CodeSpace::new()
+    .insert_block(
+      Block::new()
+        .set_signature(Some(BlockSignature::Function {
+          visibility: Some(SignatureVisibility::Pub),
+          is_async: false,
+          name: String::from("process"),
+          generics: vec![String::from("T"), String::from("U")],
+          params: vec![(String::from("item"), String::from("T"))],
+          return_type: Some(String::from("U")),
+          where_clauses: vec![
+            (String::from("T"), String::from("Clone + Send + Sync + 'static + std::fmt::Debug")),
+            (String::from("U"), String::from("Default + std::fmt::Debug + PartialEq + Eq")),
+          ],
+        }))
+        .insert_line("todo!()"),
+    )
+    .to_string();
+
+  assert_eq!(
+    code,
+    "pub fn process<T, U>(item: T) -> U\n\
+     where\n  \
+       T: Clone + Send + Sync + 'static + std::fmt::Debug,\n  \
+       U: Default + std::fmt::Debug + PartialEq + Eq,\n\
+     {\n  \
+       todo!()\n\
+     }\n"
+  );
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}
+
+#[test]
+fn test_doc_comments_and_attributes() {
+  let mut file = helper::prepare_output_dir("doc_comments_and_attributes");
+
+  let code = CodeSpace::new()
+    .insert_doc("A crate-level overview of what this generated module does.", true)
+    .insert_new_line()
+    .insert_comment("helper types below")
+    .insert_doc(
+      "A point in two-dimensional space, used throughout this module to describe where a shape \
+       is anchored.",
+      false,
+    )
+    .insert_attribute("derive(Debug, Clone)")
+    .insert_block(
+      Block::new()
+        .set_signature(Some(BlockSignature::Custom(String::from("struct Point"))))
+        .insert_line("x: i32,")
+        .insert_line("y: i32,"),
+    )
+    .to_string();
+
+  assert_eq!(
+    code,
+    "//! A crate-level overview of what this generated module does.\n\
+     \n\
+     // helper types below\n\
+     /// A point in two-dimensional space, used throughout this module to describe where a shape is\n\
+     /// anchored.\n\
+     #[derive(Debug, Clone)]\n\
+     struct Point {\n  \
+       x: i32,\n  \
+       y: i32,\n\
+     }\n"
+  );
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}
+
+#[test]
+fn test_doc_comment_wrap_width() {
+  let mut file = helper::prepare_output_dir("doc_comment_wrap_width");
+
+  let code = CodeSpace::new().insert_doc("", false).to_string();
+
+  assert_eq!(code, "///\n");
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}
+
+#[test]
+fn test_snippet_mode() {
+  let mut file = helper::prepare_output_dir("snippet_mode");
+
+  let mut code_space = CodeSpace::new();
+  code_space.snippet_mode = true;
+
+  let code = code_space
+    .insert_block(
+      Block::new()
+        .set_signature(Some(BlockSignature::Function {
+          visibility: Some(SignatureVisibility::Pub),
+          is_async: false,
+          name: String::from("new"),
+          generics: vec![],
+          params: vec![],
+          return_type: Some(String::from("Self")),
+          where_clauses: vec![],
+        }))
+        .set_snippet_mode(true)
+        .insert_placeholder(1, "todo!()")
+        .insert_placeholder(0, ""),
+    )
+    .to_string();
+
+  assert_eq!(code, "pub fn new() -> Self {\n  ${1:todo!()}\n  $0\n}\n");
+
+  file.write_all(code.as_bytes()).expect("Failed to write to test file");
+}